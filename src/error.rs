@@ -0,0 +1,32 @@
+use cosmwasm_std::{Response, StdError};
+use thiserror::Error;
+
+/// The result type returned by functions that produce a [`Response`].
+pub type CwTokenResponse = Result<Response, CwTokenError>;
+
+/// The result type returned by the crate's fallible helpers.
+pub type CwTokenResult<T> = Result<T, CwTokenError>;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CwTokenError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Invalid reply id {id}")]
+    InvalidReplyId { id: u64 },
+
+    #[error("Operation not supported for this token type: {0}")]
+    NotSupported(String),
+
+    #[error("Caller is not the admin of the denom")]
+    Unauthorized {},
+}
+
+impl From<CwTokenError> for StdError {
+    fn from(err: CwTokenError) -> Self {
+        match err {
+            CwTokenError::Std(e) => e,
+            e => StdError::generic_err(e.to_string()),
+        }
+    }
+}
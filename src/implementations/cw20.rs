@@ -0,0 +1,261 @@
+use crate::utils::unwrap_reply;
+use crate::{
+    Burn, CwTokenError, CwTokenResponse, CwTokenResult, Instantiate, Mint, Send, Token,
+    TransferFrom,
+};
+use cosmwasm_std::{
+    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError,
+    SubMsg, SubMsgResponse, Uint128, WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw_asset::AssetInfo;
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+/// A CW20 token, identified by the validated address of its contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Cw20(pub Addr);
+
+impl Display for Cw20 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Cw20> for AssetInfo {
+    fn from(token: Cw20) -> Self {
+        AssetInfo::Cw20(token.0)
+    }
+}
+
+impl TryFrom<AssetInfo> for Cw20 {
+    type Error = StdError;
+
+    fn try_from(asset_info: AssetInfo) -> Result<Self, Self::Error> {
+        match asset_info {
+            AssetInfo::Cw20(addr) => Ok(Cw20(addr)),
+            AssetInfo::Native(_) => Err(StdError::generic_err(
+                "Cannot convert Native asset to Cw20.",
+            )),
+            AssetInfo::Cw1155(_, _) => Err(StdError::generic_err(
+                "Cannot convert Cw1155 asset to Cw20.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<&AssetInfo> for Cw20 {
+    type Error = StdError;
+
+    fn try_from(asset_info: &AssetInfo) -> Result<Self, Self::Error> {
+        Self::try_from(asset_info.clone())
+    }
+}
+
+impl Cw20 {
+    fn execute(&self, msg: &Cw20ExecuteMsg) -> CwTokenResult<CosmosMsg> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_binary(msg)?,
+            funds: vec![],
+        }))
+    }
+}
+
+impl Token for Cw20 {
+    fn transfer<A: Into<String>>(
+        &self,
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        recipient: A,
+        amount: Uint128,
+    ) -> CwTokenResponse {
+        let msg = self.execute(&Cw20ExecuteMsg::Transfer {
+            recipient: recipient.into(),
+            amount,
+        })?;
+        Ok(Response::new().add_message(msg))
+    }
+
+    fn query_balance<A: Into<String>>(&self, deps: Deps, address: A) -> CwTokenResult<Uint128> {
+        let res: BalanceResponse = deps.querier.query_wasm_smart(
+            self.0.to_string(),
+            &Cw20QueryMsg::Balance {
+                address: address.into(),
+            },
+        )?;
+        Ok(res.balance)
+    }
+
+    fn is_native(&self) -> bool {
+        false
+    }
+}
+
+impl Send for Cw20 {
+    fn send<A: Into<String>>(
+        &self,
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        contract: A,
+        amount: Uint128,
+        msg: Binary,
+    ) -> CwTokenResponse {
+        let execute = self.execute(&Cw20ExecuteMsg::Send {
+            contract: contract.into(),
+            amount,
+            msg,
+        })?;
+        Ok(Response::new().add_message(execute))
+    }
+
+    fn send_from<A: Into<String>>(
+        &self,
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        owner: A,
+        contract: A,
+        amount: Uint128,
+        msg: Binary,
+    ) -> CwTokenResponse {
+        let execute = self.execute(&Cw20ExecuteMsg::SendFrom {
+            owner: owner.into(),
+            contract: contract.into(),
+            amount,
+            msg,
+        })?;
+        Ok(Response::new().add_message(execute))
+    }
+}
+
+impl TransferFrom for Cw20 {
+    fn transfer_from<A: Into<String>, B: Into<String>>(
+        &self,
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        from: A,
+        to: B,
+        amount: Uint128,
+    ) -> CwTokenResponse {
+        let msg = self.execute(&Cw20ExecuteMsg::TransferFrom {
+            owner: from.into(),
+            recipient: to.into(),
+            amount,
+        })?;
+        Ok(Response::new().add_message(msg))
+    }
+}
+
+impl Mint for Cw20 {
+    fn mint(&self, _deps: DepsMut, _env: &Env, recipient: &Addr, amount: Uint128) -> CwTokenResponse {
+        let msg = self.execute(&Cw20ExecuteMsg::Mint {
+            recipient: recipient.to_string(),
+            amount,
+        })?;
+        Ok(Response::new().add_message(msg))
+    }
+}
+
+impl Burn for Cw20 {
+    fn burn(
+        &self,
+        _deps: DepsMut,
+        _env: &Env,
+        _info: &MessageInfo,
+        amount: Uint128,
+    ) -> CwTokenResponse {
+        // Burn the contract's own held balance, mirroring how a bridge burns the wrapped assets it
+        // holds (and matching `OsmosisDenom::burn`, which burns the contract's own tokens).
+        let msg = self.execute(&Cw20ExecuteMsg::Burn { amount })?;
+        Ok(Response::new().add_message(msg))
+    }
+}
+
+/// Reply id under which a contract should route the cw20 `MsgInstantiateContract` reply so that
+/// the new token's address can be parsed and saved via [`Instantiate::save_asset`].
+pub const REPLY_SAVE_CW20: u64 = 14509;
+
+/// The message expected in [`Instantiate::instantiate`]'s `init_info` for a [`Cw20`]. It carries
+/// the `code_id` of the cw20 contract to instantiate alongside the standard cw20 `InstantiateMsg`,
+/// mirroring how the Wormhole bridge instantiates a wrapped asset from a known code id.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Cw20InstantiateMsg {
+    pub code_id: u64,
+    pub label: String,
+    pub init_msg: cw20_base::msg::InstantiateMsg,
+}
+
+/// Parse the `_contract_address` attribute from the `instantiate` event emitted by a successful
+/// `MsgInstantiateContract`.
+pub fn parse_cw20_addr_from_instantiate_event(response: &SubMsgResponse) -> CwTokenResult<String> {
+    let event = response
+        .events
+        .iter()
+        .find(|event| event.ty == "instantiate")
+        .ok_or_else(|| StdError::generic_err("cannot find `instantiate` event"))?;
+
+    let addr = &event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == "_contract_address")
+        .ok_or_else(|| StdError::generic_err("cannot find `_contract_address` attribute"))?
+        .value;
+
+    Ok(addr.to_string())
+}
+
+impl Instantiate for Cw20 {
+    const SAVE_REPLY_ID: u64 = REPLY_SAVE_CW20;
+
+    fn instantiate(
+        &self,
+        _deps: DepsMut,
+        _env: &Env,
+        init_info: Option<Binary>,
+    ) -> CwTokenResponse {
+        let init_info =
+            init_info.ok_or_else(|| StdError::generic_err("missing init_info for Cw20"))?;
+        let msg: Cw20InstantiateMsg = cosmwasm_std::from_binary(&init_info)?;
+
+        // The new contract's address is not known until the reply fires, where
+        // `save_asset` parses it from the `instantiate` event, mirroring how the
+        // Wormhole bridge captures the address of a freshly wrapped cw20 asset.
+        let instantiate = WasmMsg::Instantiate {
+            admin: None,
+            code_id: msg.code_id,
+            msg: to_binary(&msg.init_msg)?,
+            funds: vec![],
+            label: msg.label,
+        };
+
+        Ok(Response::new()
+            .add_submessage(SubMsg::reply_on_success(instantiate, REPLY_SAVE_CW20)))
+    }
+
+    fn save_asset(
+        deps: DepsMut,
+        _env: &Env,
+        reply: &Reply,
+        item: Item<Self>,
+    ) -> CwTokenResponse {
+        if reply.id != Self::SAVE_REPLY_ID {
+            return Err(CwTokenError::InvalidReplyId { id: reply.id });
+        }
+        let res = unwrap_reply(reply.clone())?;
+        let addr = parse_cw20_addr_from_instantiate_event(&res)?;
+        let token = Cw20(deps.api.addr_validate(&addr)?);
+
+        item.save(deps.storage, &token)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "save_cw20")
+            .add_attribute("token_addr", addr))
+    }
+}
@@ -0,0 +1,5 @@
+mod cw20;
+mod osmosis;
+
+pub use cw20::*;
+pub use osmosis::*;
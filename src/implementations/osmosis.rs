@@ -1,22 +1,31 @@
-use crate::token::{Burn, Instantiate, Mint};
+use crate::{
+    Admin, Burn, Instantiate, Metadata, Mint, Send, Token, TokenInfoResponse, TransferFrom,
+};
 use crate::utils::unwrap_reply;
-use crate::{CwTokenError, Token};
+use crate::{CwTokenError, CwTokenResponse, CwTokenResult};
+use apollo_proto_rust::cosmos::bank::v1beta1::{
+    DenomUnit, Metadata as BankMetadata, QueryDenomMetadataRequest, QueryDenomMetadataResponse,
+};
 use apollo_proto_rust::cosmos::base::v1beta1::Coin as CoinMsg;
-use apollo_proto_rust::osmosis::tokenfactory::v1beta1::{MsgBurn, MsgCreateDenom, MsgMint};
+use apollo_proto_rust::osmosis::tokenfactory::v1beta1::{
+    MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgMint, MsgSetDenomMetadata,
+    QueryDenomAuthorityMetadataRequest, QueryDenomAuthorityMetadataResponse,
+};
 use apollo_proto_rust::utils::encode;
 use apollo_proto_rust::OsmosisTypeURLs;
 use cosmwasm_std::{
-    Addr, BankMsg, Coin, CosmosMsg, DepsMut, Env, Event, QuerierWrapper, Reply, Response, StdError,
-    StdResult, SubMsg, SubMsgResponse, Uint128,
+    Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QuerierWrapper,
+    QueryRequest, Reply, Response, StdError, StdResult, SubMsg, SubMsgResponse, Uint128,
 };
-use cw_asset::AssetInfo;
 use cw_storage_plus::Item;
+use cw_asset::AssetInfo;
 use schemars::JsonSchema;
-use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 use std::fmt::Display;
 
+/// A fungible token created through the Osmosis tokenfactory module, identified by its full
+/// `factory/{creator}/{subdenom}` denom.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct OsmosisDenom(pub String);
 
@@ -63,9 +72,16 @@ impl TryFrom<&AssetInfo> for OsmosisDenom {
 }
 
 impl Token for OsmosisDenom {
-    fn transfer<A: Into<String>>(&self, to: A, amount: Uint128) -> StdResult<Response> {
+    fn transfer<A: Into<String>>(
+        &self,
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        recipient: A,
+        amount: Uint128,
+    ) -> CwTokenResponse {
         Ok(Response::new().add_message(CosmosMsg::Bank(BankMsg::Send {
-            to_address: to.into(),
+            to_address: recipient.into(),
             amount: vec![Coin {
                 denom: self.0.clone(),
                 amount,
@@ -73,26 +89,17 @@ impl Token for OsmosisDenom {
         })))
     }
 
-    fn query_balance<A: Into<String>>(
-        &self,
-        querier: &QuerierWrapper,
-        address: A,
-    ) -> StdResult<Uint128> {
-        Ok(querier.query_balance(address, self.0.clone())?.amount)
+    fn query_balance<A: Into<String>>(&self, deps: Deps, address: A) -> CwTokenResult<Uint128> {
+        Ok(deps.querier.query_balance(address, self.0.clone())?.amount)
     }
 
-    fn is_native() -> bool {
+    fn is_native(&self) -> bool {
         true
     }
 }
 
 impl Mint for OsmosisDenom {
-    fn mint<A: Into<String>, B: Into<String>>(
-        &self,
-        sender: A,
-        recipient: B,
-        amount: Uint128,
-    ) -> StdResult<Response> {
+    fn mint(&self, _deps: DepsMut, env: &Env, recipient: &Addr, amount: Uint128) -> CwTokenResponse {
         Ok(Response::new().add_messages(vec![
             CosmosMsg::Stargate {
                 type_url: OsmosisTypeURLs::Mint.to_string(),
@@ -101,11 +108,11 @@ impl Mint for OsmosisDenom {
                         denom: self.0.clone(),
                         amount: amount.to_string(),
                     }),
-                    sender: sender.into(),
+                    sender: env.contract.address.to_string(),
                 }),
             },
             CosmosMsg::Bank(BankMsg::Send {
-                to_address: recipient.into(),
+                to_address: recipient.to_string(),
                 amount: vec![Coin {
                     denom: self.0.clone(),
                     amount,
@@ -116,7 +123,17 @@ impl Mint for OsmosisDenom {
 }
 
 impl Burn for OsmosisDenom {
-    fn burn<A: Into<String>>(&self, sender: A, amount: Uint128) -> StdResult<Response> {
+    fn burn(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        amount: Uint128,
+    ) -> CwTokenResponse {
+        // `burn` carries the message sender, so it can enforce the admin check itself. `mint` and
+        // `set_metadata` cannot (they have no `info`), so callers must gate those via `assert_admin`.
+        self.assert_admin(deps.as_ref(), info.sender.as_str())?;
+
         Ok(Response::new().add_message(CosmosMsg::Stargate {
             type_url: OsmosisTypeURLs::Burn.to_string(),
             value: encode(MsgBurn {
@@ -124,20 +141,75 @@ impl Burn for OsmosisDenom {
                     denom: self.0.clone(),
                     amount: amount.to_string(),
                 }),
-                sender: sender.into(),
+                sender: env.contract.address.to_string(),
             }),
         }))
     }
 }
+
+impl Send for OsmosisDenom {
+    fn send<A: Into<String>>(
+        &self,
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _contract: A,
+        _amount: Uint128,
+        _msg: Binary,
+    ) -> CwTokenResponse {
+        Err(CwTokenError::NotSupported(
+            "send is not supported for tokenfactory denoms".to_string(),
+        ))
+    }
+
+    fn send_from<A: Into<String>>(
+        &self,
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _owner: A,
+        _contract: A,
+        _amount: Uint128,
+        _msg: Binary,
+    ) -> CwTokenResponse {
+        Err(CwTokenError::NotSupported(
+            "send_from is not supported for tokenfactory denoms".to_string(),
+        ))
+    }
+}
+
+impl TransferFrom for OsmosisDenom {
+    fn transfer_from<A: Into<String>, B: Into<String>>(
+        &self,
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _from: A,
+        _to: B,
+        _amount: Uint128,
+    ) -> CwTokenResponse {
+        Err(CwTokenError::NotSupported(
+            "transfer_from is not supported for tokenfactory denoms".to_string(),
+        ))
+    }
+}
+
+/// The message expected in [`Instantiate::instantiate`]'s `init_info` for an [`OsmosisDenom`],
+/// carrying the subdenom to create under the instantiating contract.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-pub struct OsmosisDenomInfo {
-    pub denom: String,
-    pub sender: String,
+pub struct OsmosisDenomInstantiateMsg {
+    pub subdenom: String,
 }
 
+/// Reply id under which a contract should route the `MsgCreateDenom` reply so that the created
+/// denom can be parsed and saved via [`Instantiate::save_asset`].
 pub const REPLY_SAVE_OSMOSIS_DENOM: u64 = 14508;
 
-fn parse_osmosis_denom_from_instantiate_event(response: SubMsgResponse) -> StdResult<String> {
+/// Parse the `new_token_denom` attribute from the `create_denom` event emitted by a successful
+/// `MsgCreateDenom`.
+pub fn parse_osmosis_denom_from_instantiate_event(
+    response: &SubMsgResponse,
+) -> CwTokenResult<String> {
     let event = response
         .events
         .iter()
@@ -155,44 +227,191 @@ fn parse_osmosis_denom_from_instantiate_event(response: SubMsgResponse) -> StdRe
 }
 
 impl Instantiate for OsmosisDenom {
-    fn instantiate<T: Serialize + DeserializeOwned>(&self, init_info: T) -> StdResult<Response> {
-        OsmosisDenomInfo::from(init_info.try_into()?);
-        Ok(Response::new().add_messages(vec![
-            CosmosMsg::Stargate {
-                type_url: OsmosisTypeURLs::Mint.to_string(),
-                value: encode(MsgMint {
-                    amount: Some(CoinMsg {
-                        denom: self.0.clone(),
-                        amount: init_info.amount,
-                    }),
-                    sender: sender.into(),
-                }),
-            },
-            CosmosMsg::Bank(BankMsg::Send {
-                to_address: recipient.into(),
-                amount: vec![Coin {
-                    denom: self.0.clone(),
-                    amount,
-                }],
+    const SAVE_REPLY_ID: u64 = REPLY_SAVE_OSMOSIS_DENOM;
+
+    fn instantiate(
+        &self,
+        _deps: DepsMut,
+        env: &Env,
+        init_info: Option<Binary>,
+    ) -> CwTokenResponse {
+        let init_info =
+            init_info.ok_or_else(|| StdError::generic_err("missing init_info for OsmosisDenom"))?;
+        let msg: OsmosisDenomInstantiateMsg = cosmwasm_std::from_binary(&init_info)?;
+
+        let create_denom = CosmosMsg::Stargate {
+            type_url: OsmosisTypeURLs::CreateDenom.to_string(),
+            value: encode(MsgCreateDenom {
+                sender: env.contract.address.to_string(),
+                subdenom: msg.subdenom,
             }),
-        ]))
+        };
+
+        Ok(Response::new()
+            .add_submessage(SubMsg::reply_on_success(create_denom, REPLY_SAVE_OSMOSIS_DENOM)))
     }
 
-    fn save_asset<T: Serialize + DeserializeOwned>(
+    fn save_asset(
         deps: DepsMut,
-        env: &Env,
+        _env: &Env,
         reply: &Reply,
-        item: Item<T>,
-    ) -> Result<Response, CwTokenError> {
-        todo!()
+        item: Item<Self>,
+    ) -> CwTokenResponse {
+        if reply.id != Self::SAVE_REPLY_ID {
+            return Err(CwTokenError::InvalidReplyId { id: reply.id });
+        }
+        let res = unwrap_reply(reply.clone())?;
+        let denom = parse_osmosis_denom_from_instantiate_event(&res)?;
+        let token = OsmosisDenom(denom.clone());
+
+        item.save(deps.storage, &token)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "save_osmosis_denom")
+            .add_attribute("denom", denom))
+    }
+}
+
+/// Accepted token symbol length, matching `cw20-base`'s own symbol validation (3..=12 characters)
+/// so tokenfactory metadata stays consistent with the cw20 tokens it is aligned with.
+const MIN_SYMBOL_LEN: usize = 3;
+const MAX_SYMBOL_LEN: usize = 12;
+/// Accepted token name length, matching `cw20-base`'s name validation (3..=50 characters).
+const MIN_NAME_LEN: usize = 3;
+const MAX_NAME_LEN: usize = 50;
+
+impl OsmosisDenom {
+    /// The creator address embedded in a `factory/{creator}/{subdenom}` denom.
+    fn creator(&self) -> StdResult<String> {
+        self.0
+            .split('/')
+            .nth(1)
+            .map(|s| s.to_string())
+            .ok_or_else(|| StdError::generic_err("Invalid denom for OsmosisDenom."))
     }
+}
+
+impl Metadata for OsmosisDenom {
+    fn set_metadata(
+        &self,
+        name: String,
+        symbol: String,
+        display: String,
+        decimals: u32,
+        description: String,
+    ) -> CwTokenResponse {
+        // Reject, rather than silently truncate, names and symbols outside the cw20-base bounds so
+        // an oversized symbol can't collide with another after truncation.
+        if !(MIN_NAME_LEN..=MAX_NAME_LEN).contains(&name.len()) {
+            return Err(StdError::generic_err(format!(
+                "name must be between {} and {} characters",
+                MIN_NAME_LEN, MAX_NAME_LEN
+            ))
+            .into());
+        }
+        if !(MIN_SYMBOL_LEN..=MAX_SYMBOL_LEN).contains(&symbol.len()) {
+            return Err(StdError::generic_err(format!(
+                "symbol must be between {} and {} characters",
+                MIN_SYMBOL_LEN, MAX_SYMBOL_LEN
+            ))
+            .into());
+        }
 
-    fn set_admin_addr(&mut self, addr: &Addr) {
-        todo!()
+        let metadata = BankMetadata {
+            description,
+            denom_units: vec![
+                DenomUnit {
+                    denom: self.0.clone(),
+                    exponent: 0,
+                    aliases: vec![],
+                },
+                DenomUnit {
+                    denom: display.clone(),
+                    exponent: decimals,
+                    aliases: vec![],
+                },
+            ],
+            base: self.0.clone(),
+            display,
+            name,
+            symbol,
+        };
+
+        Ok(Response::new().add_message(CosmosMsg::Stargate {
+            type_url: OsmosisTypeURLs::SetDenomMetadata.to_string(),
+            value: encode(MsgSetDenomMetadata {
+                sender: self.creator()?,
+                metadata: Some(metadata),
+            }),
+        }))
+    }
+
+    fn query_metadata(&self, querier: &QuerierWrapper) -> CwTokenResult<TokenInfoResponse> {
+        let res: QueryDenomMetadataResponse = querier.query(&QueryRequest::Stargate {
+            path: "/cosmos.bank.v1beta1.Query/DenomMetadata".to_string(),
+            data: Binary(encode(QueryDenomMetadataRequest {
+                denom: self.0.clone(),
+            })),
+        })?;
+
+        let metadata = res
+            .metadata
+            .ok_or_else(|| StdError::generic_err("denom has no bank metadata"))?;
+
+        // The display unit's exponent is the number of decimals.
+        let decimals = metadata
+            .denom_units
+            .iter()
+            .find(|unit| unit.denom == metadata.display)
+            .map(|unit| unit.exponent)
+            .unwrap_or(0) as u8;
+
+        Ok(TokenInfoResponse {
+            name: metadata.name,
+            symbol: metadata.symbol,
+            decimals,
+            total_supply: None,
+        })
+    }
+}
+
+impl Admin for OsmosisDenom {
+    fn change_admin<A: Into<String>>(&self, new_admin: A) -> CwTokenResponse {
+        Ok(Response::new().add_message(CosmosMsg::Stargate {
+            type_url: OsmosisTypeURLs::ChangeAdmin.to_string(),
+            value: encode(MsgChangeAdmin {
+                sender: self.creator()?,
+                denom: self.0.clone(),
+                new_admin: new_admin.into(),
+            }),
+        }))
+    }
+
+    fn query_admin(&self, querier: &QuerierWrapper) -> CwTokenResult<String> {
+        let res: QueryDenomAuthorityMetadataResponse =
+            querier.query(&QueryRequest::Stargate {
+                path: "/osmosis.tokenfactory.v1beta1.Query/DenomAuthorityMetadata".to_string(),
+                data: Binary(encode(QueryDenomAuthorityMetadataRequest {
+                    denom: self.0.clone(),
+                })),
+            })?;
+
+        let admin = res
+            .authority_metadata
+            .ok_or_else(|| StdError::generic_err("denom has no authority metadata"))?
+            .admin;
+
+        Ok(admin)
+    }
+
+    fn assert_admin(&self, deps: Deps, sender: &str) -> CwTokenResult<()> {
+        if self.query_admin(&deps.querier)? != sender {
+            return Err(CwTokenError::Unauthorized {});
+        }
+        Ok(())
     }
 }
 
 // TODO:
-// * Verify owner function on OsmosisDenom
 // * More useful functions?
 // * Implement queries as trait
@@ -85,7 +85,11 @@
 mod error;
 pub mod implementations;
 mod token;
+mod token_info;
+mod token_list;
 mod utils;
 
 pub use error::*;
 pub use token::*;
+pub use token_info::*;
+pub use token_list::*;
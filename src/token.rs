@@ -1,5 +1,11 @@
-use cosmwasm_std::{Addr, Binary, Deps, DepsMut, Env, MessageInfo, StdResult, Uint128};
+use cosmwasm_std::{
+    Addr, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Reply, StdResult, Uint128,
+};
+use cw_storage_plus::Item;
 
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 use crate::{CwTokenResponse, CwTokenResult};
@@ -10,6 +16,8 @@ pub trait Instantiate {
     /// entry point of the contract, to instantiate a new token.
     ///
     /// ## Arguments
+    /// - `env`: The contract's [`Env`], used e.g. to set the creator of a tokenfactory denom to
+    ///        the instantiating contract's own address.
     /// - `init_info`: The information needed to instantiate the token as a Binary.
     ///        It is up to the implementation to deserialize this and to the caller
     ///        to serialize a proper struct matching the needs of specific implementation.
@@ -30,10 +38,35 @@ pub trait Instantiate {
     ///     msg: InstantiateMsg,
     /// ) -> Result<Response, ContractError> {
     ///     let my_token = MyToken::new(...);
-    ///     my_token.instantiate(deps, to_binary(&msg.init_info)?)
+    ///     my_token.instantiate(deps, &env, Some(to_binary(&msg.init_info)?))
     /// }
     /// ```
-    fn instantiate(&self, deps: DepsMut, init_info: Option<Binary>) -> CwTokenResponse;
+    fn instantiate(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        init_info: Option<Binary>,
+    ) -> CwTokenResponse;
+
+    /// The reply id the implementation's `instantiate` submessage is dispatched with, and against
+    /// which [`Instantiate::save_asset`] validates incoming replies.
+    const SAVE_REPLY_ID: u64;
+
+    /// Recover the token created by [`Instantiate::instantiate`] from its reply and persist it.
+    ///
+    /// This is the single reply entry point a contract registers: forward the whole [`Reply`] to
+    /// it and it validates `reply.id` against [`Instantiate::SAVE_REPLY_ID`] (erroring with
+    /// [`crate::CwTokenError::InvalidReplyId`] on a misrouted reply), parses the address or denom
+    /// of the newly created token out of the submessage events, constructs the token, and stores
+    /// it in `item` so the contract can use it in subsequent calls.
+    fn save_asset(
+        deps: DepsMut,
+        env: &Env,
+        reply: &Reply,
+        item: Item<Self>,
+    ) -> CwTokenResponse
+    where
+        Self: Sized + Serialize + DeserializeOwned;
 }
 
 pub trait Token: Display {
@@ -48,7 +81,7 @@ pub trait Token: Display {
 
     fn query_balance<A: Into<String>>(&self, deps: Deps, address: A) -> CwTokenResult<Uint128>;
 
-    fn is_native() -> bool;
+    fn is_native(&self) -> bool;
 }
 
 pub trait Send {
@@ -100,10 +133,65 @@ pub trait Burn {
     ) -> CwTokenResponse;
 }
 
+/// Human-readable token metadata, aligned with cw20's `TokenInfoResponse` so that tokenfactory
+/// denoms can be displayed in wallets and explorers the same way cw20 tokens are.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TokenInfoResponse {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: Option<Uint128>,
+}
+
+/// A trait for tokens that carry human-readable metadata, such as the bank module's denom
+/// metadata for tokenfactory denoms.
+pub trait Metadata {
+    /// Set the denom's bank metadata, building `denom_units` with a base unit at exponent 0 and a
+    /// display unit at `decimals`.
+    fn set_metadata(
+        &self,
+        name: String,
+        symbol: String,
+        display: String,
+        decimals: u32,
+        description: String,
+    ) -> CwTokenResponse;
+
+    /// Query the denom's metadata from the bank module.
+    fn query_metadata(&self, querier: &QuerierWrapper) -> CwTokenResult<TokenInfoResponse>;
+}
+
+/// A trait for tokens whose privileged operations (mint, burn, metadata changes) are gated behind
+/// an on-chain admin, such as the authority metadata of a tokenfactory denom.
+///
+/// [`Burn::burn`] receives the [`MessageInfo`] and calls [`Admin::assert_admin`] with
+/// `info.sender` itself. [`Mint::mint`] and [`Metadata::set_metadata`] do **not** — their
+/// signatures do not carry the external message sender, so they cannot know who is ultimately
+/// invoking them. A contract that wants to restrict those operations to the denom admin must call
+/// [`Admin::assert_admin`] with `info.sender` itself before constructing the messages.
+pub trait Admin {
+    /// Transfer the denom's admin rights to `new_admin`.
+    fn change_admin<A: Into<String>>(&self, new_admin: A) -> CwTokenResponse;
+
+    /// Query the denom's current admin from its authority metadata.
+    fn query_admin(&self, querier: &QuerierWrapper) -> CwTokenResult<String>;
+
+    /// Error with [`CwTokenError::Unauthorized`] unless `sender` is the denom's current admin.
+    /// Call this from the contract, passing `info.sender`, before `mint` or `set_metadata` to gate
+    /// those privileged operations against the caller; `burn` already performs this check.
+    fn assert_admin(&self, deps: Deps, sender: &str) -> CwTokenResult<()>;
+}
+
 // Validates that the `amount` amount of tokens were received by the contract.
 // E.g. if it is a native token, assert that this amount exists in info.funds,
 // and that if it is a Cw4626 that the user has this amount of tokens in their
 // balance.
 pub trait AssertReceived {
-    fn assert_received(&self, deps: Deps, info: &MessageInfo, amount: Uint128) -> StdResult<()>;
+    fn assert_received(
+        &self,
+        deps: Deps,
+        env: &Env,
+        info: &MessageInfo,
+        amount: Uint128,
+    ) -> StdResult<()>;
 }
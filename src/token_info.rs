@@ -0,0 +1,273 @@
+use cosmwasm_std::{
+    Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
+    StdError, StdResult, Uint128,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+
+use cw_asset::AssetInfo;
+
+use crate::implementations::{Cw20, OsmosisDenom};
+use crate::{
+    AssertReceived, Burn, CwTokenError, CwTokenResponse, CwTokenResult, Mint, Send, Token,
+    TransferFrom,
+};
+
+/// A runtime-dispatched token, so that a contract can accept whichever token kind a user passes
+/// in a message without being generic over a single [`Token`] implementor.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenInfo {
+    Native(String),
+    Cw20(Addr),
+    TokenFactory(OsmosisDenom),
+}
+
+impl Display for TokenInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenInfo::Native(denom) => write!(f, "native:{}", denom),
+            TokenInfo::Cw20(addr) => write!(f, "cw20:{}", addr),
+            TokenInfo::TokenFactory(denom) => write!(f, "tokenfactory:{}", denom),
+        }
+    }
+}
+
+impl Token for TokenInfo {
+    fn transfer<A: Into<String>>(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: A,
+        amount: Uint128,
+    ) -> CwTokenResponse {
+        match self {
+            TokenInfo::Native(denom) => {
+                Ok(Response::new().add_message(CosmosMsg::Bank(BankMsg::Send {
+                    to_address: recipient.into(),
+                    amount: vec![Coin {
+                        denom: denom.clone(),
+                        amount,
+                    }],
+                })))
+            }
+            TokenInfo::Cw20(addr) => {
+                Cw20(addr.clone()).transfer(deps, env, info, recipient, amount)
+            }
+            TokenInfo::TokenFactory(denom) => denom.transfer(deps, env, info, recipient, amount),
+        }
+    }
+
+    fn query_balance<A: Into<String>>(&self, deps: Deps, address: A) -> CwTokenResult<Uint128> {
+        match self {
+            TokenInfo::Native(denom) => {
+                Ok(deps.querier.query_balance(address, denom.clone())?.amount)
+            }
+            TokenInfo::Cw20(addr) => Cw20(addr.clone()).query_balance(deps, address),
+            TokenInfo::TokenFactory(denom) => denom.query_balance(deps, address),
+        }
+    }
+
+    fn is_native(&self) -> bool {
+        match self {
+            TokenInfo::Native(_) | TokenInfo::TokenFactory(_) => true,
+            TokenInfo::Cw20(_) => false,
+        }
+    }
+}
+
+impl Send for TokenInfo {
+    fn send<A: Into<String>>(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        contract: A,
+        amount: Uint128,
+        msg: Binary,
+    ) -> CwTokenResponse {
+        match self {
+            TokenInfo::Cw20(addr) => {
+                Cw20(addr.clone()).send(deps, env, info, contract, amount, msg)
+            }
+            TokenInfo::Native(_) => Err(CwTokenError::NotSupported(
+                "send is not supported for native tokens".to_string(),
+            )),
+            TokenInfo::TokenFactory(denom) => denom.send(deps, env, info, contract, amount, msg),
+        }
+    }
+
+    fn send_from<A: Into<String>>(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        owner: A,
+        contract: A,
+        amount: Uint128,
+        msg: Binary,
+    ) -> CwTokenResponse {
+        match self {
+            TokenInfo::Cw20(addr) => {
+                Cw20(addr.clone()).send_from(deps, env, info, owner, contract, amount, msg)
+            }
+            TokenInfo::Native(_) => Err(CwTokenError::NotSupported(
+                "send_from is not supported for native tokens".to_string(),
+            )),
+            TokenInfo::TokenFactory(denom) => {
+                denom.send_from(deps, env, info, owner, contract, amount, msg)
+            }
+        }
+    }
+}
+
+impl TransferFrom for TokenInfo {
+    fn transfer_from<A: Into<String>, B: Into<String>>(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        from: A,
+        to: B,
+        amount: Uint128,
+    ) -> CwTokenResponse {
+        match self {
+            TokenInfo::Cw20(addr) => {
+                Cw20(addr.clone()).transfer_from(deps, env, info, from, to, amount)
+            }
+            TokenInfo::Native(_) => Err(CwTokenError::NotSupported(
+                "transfer_from is not supported for native tokens".to_string(),
+            )),
+            TokenInfo::TokenFactory(denom) => {
+                denom.transfer_from(deps, env, info, from, to, amount)
+            }
+        }
+    }
+}
+
+impl Mint for TokenInfo {
+    fn mint(&self, deps: DepsMut, env: &Env, recipient: &Addr, amount: Uint128) -> CwTokenResponse {
+        match self {
+            TokenInfo::Cw20(addr) => Cw20(addr.clone()).mint(deps, env, recipient, amount),
+            TokenInfo::TokenFactory(denom) => denom.mint(deps, env, recipient, amount),
+            TokenInfo::Native(_) => Err(CwTokenError::NotSupported(
+                "mint is not supported for native tokens".to_string(),
+            )),
+        }
+    }
+}
+
+impl Burn for TokenInfo {
+    fn burn(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+        amount: Uint128,
+    ) -> CwTokenResponse {
+        match self {
+            TokenInfo::Cw20(addr) => Cw20(addr.clone()).burn(deps, env, info, amount),
+            TokenInfo::TokenFactory(denom) => denom.burn(deps, env, info, amount),
+            TokenInfo::Native(_) => Err(CwTokenError::NotSupported(
+                "burn is not supported for native tokens".to_string(),
+            )),
+        }
+    }
+}
+
+impl AssertReceived for TokenInfo {
+    fn assert_received(
+        &self,
+        deps: Deps,
+        env: &Env,
+        info: &MessageInfo,
+        amount: Uint128,
+    ) -> StdResult<()> {
+        match self {
+            // Native and tokenfactory coins must be attached to the message.
+            TokenInfo::Native(denom) => assert_sent_native(info, denom, amount),
+            TokenInfo::TokenFactory(denom) => assert_sent_native(info, &denom.0, amount),
+            // Cw20 tokens are moved separately (via `transfer_from`/`send`), so check that the
+            // contract is actually holding at least `amount` of them.
+            TokenInfo::Cw20(addr) => {
+                let balance = Cw20(addr.clone())
+                    .query_balance(deps, env.contract.address.to_string())?;
+                if balance < amount {
+                    return Err(StdError::generic_err(format!(
+                        "expected {} of cw20 {} to be held, contract holds {}",
+                        amount, addr, balance
+                    )));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn assert_sent_native(info: &MessageInfo, denom: &str, amount: Uint128) -> StdResult<()> {
+    let sent = info
+        .funds
+        .iter()
+        .find(|coin| coin.denom == denom)
+        .map(|coin| coin.amount)
+        .unwrap_or_default();
+
+    if sent < amount {
+        return Err(StdError::generic_err(format!(
+            "expected {} {} to be sent, received {}",
+            amount, denom, sent
+        )));
+    }
+    Ok(())
+}
+
+/// The unchecked counterpart of [`TokenInfo`], holding unverified addresses and denoms so that it
+/// can be embedded directly in a contract's `ExecuteMsg`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenInfoUnchecked {
+    Native(String),
+    Cw20(String),
+    TokenFactory(String),
+}
+
+impl TokenInfoUnchecked {
+    /// Validate the addresses and denoms, returning a checked [`TokenInfo`]. If
+    /// `accepted_denoms` is provided, native denoms must be contained in it.
+    pub fn check(
+        &self,
+        api: &dyn Api,
+        accepted_denoms: Option<&[&str]>,
+    ) -> CwTokenResult<TokenInfo> {
+        match self {
+            TokenInfoUnchecked::Native(denom) => {
+                if let Some(whitelist) = accepted_denoms {
+                    if !whitelist.contains(&denom.as_str()) {
+                        return Err(CwTokenError::Std(StdError::generic_err(format!(
+                            "native denom `{}` is not in the accepted denom list",
+                            denom
+                        ))));
+                    }
+                }
+                Ok(TokenInfo::Native(denom.clone()))
+            }
+            TokenInfoUnchecked::Cw20(addr) => Ok(TokenInfo::Cw20(api.addr_validate(addr)?)),
+            TokenInfoUnchecked::TokenFactory(denom) => Ok(TokenInfo::TokenFactory(
+                OsmosisDenom::try_from(AssetInfo::Native(denom.clone()))?,
+            )),
+        }
+    }
+}
+
+impl From<TokenInfo> for TokenInfoUnchecked {
+    fn from(info: TokenInfo) -> Self {
+        match info {
+            TokenInfo::Native(denom) => TokenInfoUnchecked::Native(denom),
+            TokenInfo::Cw20(addr) => TokenInfoUnchecked::Cw20(addr.to_string()),
+            TokenInfo::TokenFactory(denom) => TokenInfoUnchecked::TokenFactory(denom.0),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use cosmwasm_std::{Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt::{self, Display};
+
+use crate::{AssertReceived, Burn, CwTokenResult, Mint, Send, Token, TokenInfo};
+
+/// A set of `(TokenInfo, amount)` pairs, for contracts that move several tokens in one call. Entries
+/// are deduplicated by token, summing their amounts, analogous to cw-asset's `AssetList`.
+#[derive(Serialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct TokenList(Vec<(TokenInfo, Uint128)>);
+
+impl<'de> Deserialize<'de> for TokenList {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<(TokenInfo, Uint128)>::deserialize(deserializer)?.into())
+    }
+}
+
+impl Display for TokenList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = self
+            .0
+            .iter()
+            .map(|(token, amount)| format!("{}:{}", token, amount))
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(f, "{}", s)
+    }
+}
+
+impl From<Vec<(TokenInfo, Uint128)>> for TokenList {
+    fn from(tokens: Vec<(TokenInfo, Uint128)>) -> Self {
+        let mut list = TokenList(vec![]);
+        for (token, amount) in tokens {
+            list.add(token, amount);
+        }
+        list
+    }
+}
+
+impl TokenList {
+    /// Add `amount` of `token` to the list, merging with any existing entry for the same token.
+    pub fn add(&mut self, token: TokenInfo, amount: Uint128) {
+        if let Some((_, existing)) = self.0.iter_mut().find(|(t, _)| t == &token) {
+            *existing += amount;
+        } else {
+            self.0.push((token, amount));
+        }
+    }
+
+    /// The underlying `(token, amount)` pairs.
+    pub fn into_vec(self) -> Vec<(TokenInfo, Uint128)> {
+        self.0
+    }
+
+    fn flatten_msgs<F>(&self, mut f: F) -> CwTokenResult<Vec<CosmosMsg>>
+    where
+        F: FnMut(&TokenInfo, Uint128) -> CwTokenResult<Vec<CosmosMsg>>,
+    {
+        let mut msgs = vec![];
+        for (token, amount) in &self.0 {
+            msgs.extend(f(token, *amount)?);
+        }
+        Ok(msgs)
+    }
+
+    /// Messages transferring every token in the list to `recipient`.
+    pub fn transfer_msgs(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: impl Into<String>,
+    ) -> CwTokenResult<Vec<CosmosMsg>> {
+        let recipient = recipient.into();
+        self.flatten_msgs(|token, amount| {
+            Ok(token
+                .transfer(deps.branch(), env.clone(), info.clone(), recipient.clone(), amount)?
+                .messages
+                .into_iter()
+                .map(|sub| sub.msg)
+                .collect())
+        })
+    }
+
+    /// Messages sending every token in the list to `contract` with the attached `msg`.
+    pub fn send_msgs(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        contract: impl Into<String>,
+        msg: Binary,
+    ) -> CwTokenResult<Vec<CosmosMsg>> {
+        let contract = contract.into();
+        self.flatten_msgs(|token, amount| {
+            Ok(token
+                .send(deps.branch(), env.clone(), info.clone(), contract.clone(), amount, msg.clone())?
+                .messages
+                .into_iter()
+                .map(|sub| sub.msg)
+                .collect())
+        })
+    }
+
+    /// Messages minting every token in the list to `recipient`.
+    pub fn mint_msgs(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        recipient: &cosmwasm_std::Addr,
+    ) -> CwTokenResult<Vec<CosmosMsg>> {
+        self.flatten_msgs(|token, amount| {
+            Ok(token
+                .mint(deps.branch(), env, recipient, amount)?
+                .messages
+                .into_iter()
+                .map(|sub| sub.msg)
+                .collect())
+        })
+    }
+
+    /// Messages burning every token in the list.
+    pub fn burn_msgs(
+        &self,
+        mut deps: DepsMut,
+        env: &Env,
+        info: &MessageInfo,
+    ) -> CwTokenResult<Vec<CosmosMsg>> {
+        self.flatten_msgs(|token, amount| {
+            Ok(token
+                .burn(deps.branch(), env, info, amount)?
+                .messages
+                .into_iter()
+                .map(|sub| sub.msg)
+                .collect())
+        })
+    }
+
+    /// Validate that every token in the list was received, checking native and tokenfactory coins
+    /// against `info.funds` and cw20 tokens against the contract's holdings.
+    pub fn assert_received(
+        &self,
+        deps: Deps,
+        env: &Env,
+        info: &MessageInfo,
+    ) -> CwTokenResult<()> {
+        for (token, amount) in &self.0 {
+            token.assert_received(deps, env, info, *amount)?;
+        }
+        Ok(())
+    }
+}
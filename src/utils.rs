@@ -0,0 +1,9 @@
+use cosmwasm_std::{Reply, StdError, SubMsgResponse};
+
+use crate::CwTokenResult;
+
+/// Unwrap the [`SubMsgResponse`] from a [`Reply`], turning a failed submessage
+/// into an error.
+pub fn unwrap_reply(reply: Reply) -> CwTokenResult<SubMsgResponse> {
+    Ok(reply.result.into_result().map_err(StdError::generic_err)?)
+}